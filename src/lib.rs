@@ -104,6 +104,23 @@ impl<T, const N: usize> Iterator for RingBufferIter<T, N> {
         self.0.size -= 1;
         Some(unsafe { self.0.items[index].assume_init_read() })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.size, Some(self.0.size))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for RingBufferIter<T, N> {}
+
+impl<T, const N: usize> DoubleEndedIterator for RingBufferIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.0.size == 0 {
+            return None;
+        }
+        let index = (self.0.first + self.0.size - 1) % N;
+        self.0.size -= 1;
+        Some(unsafe { self.0.items[index].assume_init_read() })
+    }
 }
 
 /// Produced by the [iter](RingBuffer::iter) method of the [RingBuffer].
@@ -127,6 +144,122 @@ impl<'a, T, const N: usize> Iterator for RingBufferIterRef<'a, T, N> {
         self.size -= 1;
         Some(unsafe { self.items[index].assume_init_ref() })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.size, Some(self.size))
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for RingBufferIterRef<'a, T, N> {}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for RingBufferIterRef<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
+        let index = (self.first + self.size - 1) % N;
+        self.size -= 1;
+        Some(unsafe { self.items[index].assume_init_ref() })
+    }
+}
+
+/// Produced by the [iter_mut](RingBuffer::iter_mut) method of the [RingBuffer].
+///
+/// This iterates over mutable references of the [RingBuffer].
+pub struct RingBufferIterMut<'a, T, const N: usize> {
+    items: &'a mut [core::mem::MaybeUninit<T>; N],
+    size: usize,
+    first: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for RingBufferIterMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
+        let index = self.first;
+        self.first = (self.first + 1) % N;
+        self.size -= 1;
+        // Safety: We walk exactly `size` distinct logical slots starting at `first`, so we never
+        // hand out two mutable references to the same slot. Extending the borrow to `'a` is sound
+        // because each slot is visited at most once.
+        let item = unsafe { self.items[index].assume_init_mut() };
+        Some(unsafe { &mut *(item as *mut T) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.size, Some(self.size))
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for RingBufferIterMut<'a, T, N> {}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for RingBufferIterMut<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
+        let index = (self.first + self.size - 1) % N;
+        self.size -= 1;
+        // Safety: `next` and `next_back` walk toward each other and stop once they meet (`size`
+        // reaches 0), so every slot is handed out at most once.
+        let item = unsafe { self.items[index].assume_init_mut() };
+        Some(unsafe { &mut *(item as *mut T) })
+    }
+}
+
+/// Produced by the [drain](RingBuffer::drain) method of the [RingBuffer].
+///
+/// Yields the owned elements of the drained range in logical order. On drop the gap is closed by
+/// shifting the surviving tail down, so the buffer stays contiguous and correctly initialized.
+pub struct Drain<'a, T, const N: usize> {
+    storage: &'a mut ItemStorage<T, N>,
+    /// Original offset of the first logical element.
+    first: usize,
+    /// Next logical index to yield.
+    idx: usize,
+    /// Exclusive end of the drained range.
+    end: usize,
+    /// Number of surviving elements after the drained range.
+    tail: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for Drain<'a, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.idx >= self.end {
+            return None;
+        }
+        let index = (self.first + self.idx) % N;
+        self.idx += 1;
+        Some(unsafe { self.storage.items[index].assume_init_read() })
+    }
+}
+
+impl<'a, T, const N: usize> Drop for Drain<'a, T, N> {
+    fn drop(&mut self) {
+        // Drop any drained elements that were never yielded.
+        while self.idx < self.end {
+            let index = (self.first + self.idx) % N;
+            self.idx += 1;
+            unsafe { self.storage.items[index].assume_init_drop() };
+        }
+        // Close the gap: shift the surviving tail down into the drained region. The drain start was
+        // recorded as `size` at construction (see `drain`). Reading each source before overwriting
+        // its slot keeps wrap-around overlaps sound.
+        let drain_start = self.storage.size;
+        for k in 0..self.tail {
+            let src = (self.first + self.end + k) % N;
+            let dst = (self.first + drain_start + k) % N;
+            let value = unsafe { self.storage.items[src].assume_init_read() };
+            self.storage.items[dst].write(value);
+        }
+        self.storage.size = drain_start + self.tail;
+        self.storage.first = self.first;
+    }
 }
 
 impl<T, const N: usize> IntoIterator for RingBuffer<T, N> {
@@ -160,9 +293,15 @@ where
 
 impl<T, const N: usize> RingBuffer<T, N> {
     /// Creates a new empty [RingBuffer]
-    pub fn new() -> Self {
+    ///
+    /// This is a `const fn`, so buffers can be constructed in `const`/`static` context:
+    /// ```
+    /// # use circ_buffer::*;
+    /// const BUF: RingBuffer<u32, 8> = RingBuffer::new();
+    /// ```
+    pub const fn new() -> Self {
         Self(ItemStorage {
-            items: unsafe { core::mem::MaybeUninit::uninit().assume_init() },
+            items: [const { core::mem::MaybeUninit::uninit() }; N],
             first: 0,
             size: 0,
         })
@@ -187,6 +326,53 @@ impl<T, const N: usize> core::ops::Index<usize> for RingBuffer<T, N> {
     }
 }
 
+impl<T, const N: usize> core::ops::IndexMut<usize> for RingBuffer<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.0.index_mut(index)
+    }
+}
+
+impl<T, const N: usize> PartialEq for RingBuffer<T, N>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.size == other.0.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<T, const N: usize> Eq for RingBuffer<T, N> where T: Eq {}
+
+impl<T, const N: usize> core::hash::Hash for RingBuffer<T, N>
+where
+    T: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.size.hash(state);
+        for element in self.iter() {
+            element.hash(state);
+        }
+    }
+}
+
+impl<T, const N: usize> PartialOrd for RingBuffer<T, N>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T, const N: usize> Ord for RingBuffer<T, N>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
 impl<T, const N: usize> Drop for ItemStorage<T, N> {
     fn drop(&mut self) {
         for n in 0..self.size {
@@ -202,13 +388,22 @@ impl<T, const N: usize> Drop for ItemStorage<T, N> {
 impl<T, const N: usize> core::ops::Index<usize> for ItemStorage<T, N> {
     type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
-        if index > self.size {
-            panic!("index > size");
+        if index >= self.size {
+            panic!("index >= size");
         }
         unsafe { core::mem::MaybeUninit::assume_init_ref(&self.items[(self.first + index) % N]) }
     }
 }
 
+impl<T, const N: usize> core::ops::IndexMut<usize> for ItemStorage<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if index >= self.size {
+            panic!("index >= size");
+        }
+        unsafe { core::mem::MaybeUninit::assume_init_mut(&mut self.items[(self.first + index) % N]) }
+    }
+}
+
 impl<T, const N: usize> RingBuffer<T, N> {
     /// Append one element to the buffer.
     ///
@@ -239,6 +434,128 @@ impl<T, const N: usize> RingBuffer<T, N> {
         }
     }
 
+    /// Prepend one element to the front of the buffer.
+    ///
+    /// This will not grow the buffer but instead replace the element at the back when the maximum
+    /// size is reached.
+    /// ```
+    /// # use circ_buffer::*;
+    /// let mut circ_buffer = RingBuffer::<i64, 3>::default();
+    /// circ_buffer.push(1);
+    /// circ_buffer.push(2);
+    /// circ_buffer.push_front(0);
+    /// assert_eq!(circ_buffer.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+    /// // The buffer is full, so pushing to the front drops the back entry.
+    /// circ_buffer.push_front(-1);
+    /// assert_eq!(circ_buffer.iter().collect::<Vec<_>>(), vec![&-1, &0, &1]);
+    /// ```
+    pub fn push_front(&mut self, new_item: T) {
+        if N > 0 {
+            let first = (self.0.first + N - 1) % N;
+            if self.0.size == N {
+                // The slot at the new `first` currently holds the old back element.
+                unsafe { self.0.items.get_unchecked_mut(first).assume_init_drop() };
+            }
+            self.0.items[first].write(new_item);
+            self.0.first = first;
+            self.0.size = N.min(self.0.size + 1);
+        }
+    }
+
+    /// Remove and return the element at the front of the buffer.
+    ///
+    /// ```
+    /// # use circ_buffer::*;
+    /// let mut circ_buffer = RingBuffer::<i64, 4>::default();
+    /// circ_buffer.push(1);
+    /// circ_buffer.push(2);
+    /// assert_eq!(circ_buffer.pop_front(), Some(1));
+    /// assert_eq!(circ_buffer.pop_front(), Some(2));
+    /// assert_eq!(circ_buffer.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if N == 0 || self.0.size == 0 {
+            return None;
+        }
+        let index = self.0.first;
+        self.0.first = (self.0.first + 1) % N;
+        self.0.size -= 1;
+        Some(unsafe { self.0.items[index].assume_init_read() })
+    }
+
+    /// Remove and return the element at the back of the buffer.
+    ///
+    /// ```
+    /// # use circ_buffer::*;
+    /// let mut circ_buffer = RingBuffer::<i64, 4>::default();
+    /// circ_buffer.push(1);
+    /// circ_buffer.push(2);
+    /// assert_eq!(circ_buffer.pop_back(), Some(2));
+    /// assert_eq!(circ_buffer.pop_back(), Some(1));
+    /// assert_eq!(circ_buffer.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if N == 0 || self.0.size == 0 {
+            return None;
+        }
+        let index = (self.0.first + self.0.size - 1) % N;
+        self.0.size -= 1;
+        Some(unsafe { self.0.items[index].assume_init_read() })
+    }
+
+    /// Remove the elements in the given logical range and yield them as owned values.
+    ///
+    /// The returned [Drain] yields the drained elements front-to-back. When it is dropped the
+    /// remaining elements after the range are shifted down so the buffer stays contiguous.
+    /// ```
+    /// # use circ_buffer::*;
+    /// let mut circ_buffer = RingBuffer::<i64, 5>::default();
+    /// for i in 0..5 {
+    ///     circ_buffer.push(i);
+    /// }
+    /// let drained = circ_buffer.drain(1..3).collect::<Vec<_>>();
+    /// assert_eq!(drained, vec![1, 2]);
+    /// assert_eq!(circ_buffer.iter().collect::<Vec<_>>(), vec![&0, &3, &4]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the upper bound of the range exceeds the current size.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, N>
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        use core::ops::Bound;
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e + 1,
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => self.0.size,
+        };
+        if start > end {
+            panic!("drain start > end");
+        }
+        if end > self.0.size {
+            panic!("drain end > size");
+        }
+        let first = self.0.first;
+        let tail = self.0.size - end;
+        // Record the drain start as the logical size; this keeps the buffer forget-safe (a leaked
+        // `Drain` simply loses the trailing elements without touching uninitialized memory) and
+        // lets `Drain::drop` recover where the surviving tail should land.
+        self.0.size = start;
+        Drain {
+            storage: &mut self.0,
+            first,
+            idx: start,
+            end,
+            tail,
+        }
+    }
+
     /// Iterate over references to elements of the RingBuffer.
     pub fn iter<'a>(&'a self) -> RingBufferIterRef<'a, T, N> {
         RingBufferIterRef {
@@ -247,6 +564,106 @@ impl<T, const N: usize> RingBuffer<T, N> {
             size: self.0.size,
         }
     }
+
+    /// Iterate over mutable references to elements of the RingBuffer.
+    ///
+    /// ```
+    /// # use circ_buffer::*;
+    /// let mut circ_buffer = RingBuffer::<i64, 4>::default();
+    /// circ_buffer.push(1);
+    /// circ_buffer.push(2);
+    /// for e in circ_buffer.iter_mut() {
+    ///     *e *= 10;
+    /// }
+    /// assert_eq!(circ_buffer.iter().collect::<Vec<_>>(), vec![&10, &20]);
+    /// ```
+    pub fn iter_mut<'a>(&'a mut self) -> RingBufferIterMut<'a, T, N> {
+        RingBufferIterMut {
+            first: self.0.first,
+            size: self.0.size,
+            items: &mut self.0.items,
+        }
+    }
+
+    /// Return the initialized contents as at most two contiguous slices in logical order.
+    ///
+    /// The first slice runs from the front towards the end of the backing array and stops there if
+    /// the contents wrap; the second holds the remainder that wrapped around to the start. The
+    /// second slice is empty when the contents do not wrap; both are empty for an empty buffer.
+    /// ```
+    /// # use circ_buffer::*;
+    /// let mut circ_buffer = RingBuffer::<i64, 4>::default();
+    /// for i in 0..6 {
+    ///     circ_buffer.push(i);
+    /// }
+    /// // Logical contents [2, 3, 4, 5] stored wrapping across the array boundary.
+    /// let (front, back) = circ_buffer.as_slices();
+    /// assert_eq!([front, back].concat(), vec![2, 3, 4, 5]);
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        if N == 0 || self.0.size == 0 {
+            return (&[], &[]);
+        }
+        let end = (self.0.first + self.0.size).min(N);
+        let head = &self.0.items[self.0.first..end];
+        let tail = &self.0.items[..self.0.size - head.len()];
+        // Safety: both sub-slices cover only the initialized logical range.
+        unsafe {
+            (
+                core::slice::from_raw_parts(head.as_ptr() as *const T, head.len()),
+                core::slice::from_raw_parts(tail.as_ptr() as *const T, tail.len()),
+            )
+        }
+    }
+
+    /// Return the initialized contents as at most two contiguous mutable slices in logical order.
+    ///
+    /// See [as_slices](RingBuffer::as_slices) for the slicing scheme.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        if N == 0 || self.0.size == 0 {
+            return (&mut [], &mut []);
+        }
+        let end = (self.0.first + self.0.size).min(N);
+        let head_len = end - self.0.first;
+        let tail_len = self.0.size - head_len;
+        let (tail, head) = self.0.items.split_at_mut(self.0.first);
+        // Safety: both sub-slices cover only the initialized logical range and are disjoint.
+        unsafe {
+            (
+                core::slice::from_raw_parts_mut(head.as_mut_ptr() as *mut T, head_len),
+                core::slice::from_raw_parts_mut(tail.as_mut_ptr() as *mut T, tail_len),
+            )
+        }
+    }
+
+    /// Get a reference to the element at the given logical index, or [None] if out of bounds.
+    ///
+    /// ```
+    /// # use circ_buffer::*;
+    /// let mut circ_buffer = RingBuffer::<i64, 4>::default();
+    /// circ_buffer.push(7);
+    /// assert_eq!(circ_buffer.get(0), Some(&7));
+    /// assert_eq!(circ_buffer.get(1), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.0.size {
+            return None;
+        }
+        Some(unsafe {
+            core::mem::MaybeUninit::assume_init_ref(&self.0.items[(self.0.first + index) % N])
+        })
+    }
+
+    /// Get a mutable reference to the element at the given logical index, or [None] if out of
+    /// bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.0.size {
+            return None;
+        }
+        Some(unsafe {
+            core::mem::MaybeUninit::assume_init_mut(&mut self.0.items[(self.0.first + index) % N])
+        })
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -450,6 +867,169 @@ mod test_circ_buffer {
         }
     }
 
+    #[test]
+    fn test_iter_mut_and_get() {
+        let mut circ_buffer = RingBuffer::<_, 4>::default();
+        for i in 0..6 {
+            circ_buffer.push(i);
+        }
+        // Logical contents are now [2, 3, 4, 5] with a rotated internal offset.
+        for e in circ_buffer.iter_mut() {
+            *e += 100;
+        }
+        assert_eq!(
+            circ_buffer.iter().collect::<Vec<_>>(),
+            vec![&102, &103, &104, &105]
+        );
+        assert_eq!(circ_buffer.get(0), Some(&102));
+        assert_eq!(circ_buffer.get(3), Some(&105));
+        assert_eq!(circ_buffer.get(4), None);
+        *circ_buffer.get_mut(1).unwrap() = 0;
+        assert_eq!(circ_buffer[1], 0);
+    }
+
+    #[test]
+    fn test_push_pop_front_back() {
+        let mut circ_buffer = RingBuffer::<_, 3>::default();
+        circ_buffer.push(1);
+        circ_buffer.push(2);
+        circ_buffer.push_front(0);
+        assert_eq!(circ_buffer.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+        // Full buffer: pushing to the front evicts the back.
+        circ_buffer.push_front(-1);
+        assert_eq!(circ_buffer.iter().collect::<Vec<_>>(), vec![&-1, &0, &1]);
+        assert_eq!(circ_buffer.pop_back(), Some(1));
+        assert_eq!(circ_buffer.pop_front(), Some(-1));
+        assert_eq!(circ_buffer.pop_front(), Some(0));
+        assert_eq!(circ_buffer.pop_front(), None);
+        assert_eq!(circ_buffer.pop_back(), None);
+    }
+
+    #[test]
+    fn test_as_slices_wrapping() {
+        let mut circ_buffer = RingBuffer::<_, 4>::default();
+        for i in 0..6 {
+            circ_buffer.push(i);
+        }
+        let (front, back) = circ_buffer.as_slices();
+        assert_eq!([front, back].concat(), vec![2, 3, 4, 5]);
+        for e in circ_buffer.as_mut_slices().0 {
+            *e += 10;
+        }
+        for e in circ_buffer.as_mut_slices().1 {
+            *e += 10;
+        }
+        assert_eq!(
+            circ_buffer.iter().collect::<Vec<_>>(),
+            vec![&12, &13, &14, &15]
+        );
+    }
+
+    #[test]
+    fn test_drain_middle() {
+        let mut circ_buffer = RingBuffer::<_, 6>::default();
+        for i in 0..8 {
+            circ_buffer.push(i);
+        }
+        // Logical contents [2, 3, 4, 5, 6, 7] stored wrapping.
+        let drained = circ_buffer.drain(1..4).collect::<Vec<_>>();
+        assert_eq!(drained, vec![3, 4, 5]);
+        assert_eq!(circ_buffer.iter().collect::<Vec<_>>(), vec![&2, &6, &7]);
+    }
+
+    #[test]
+    fn test_drain_full() {
+        let mut circ_buffer = RingBuffer::<_, 4>::default();
+        for i in 0..4 {
+            circ_buffer.push(i);
+        }
+        let drained = circ_buffer.drain(..).collect::<Vec<_>>();
+        assert_eq!(drained, vec![0, 1, 2, 3]);
+        assert_eq!(circ_buffer.get_size(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_drain_out_of_bounds() {
+        let mut circ_buffer = RingBuffer::<_, 4>::default();
+        circ_buffer.push(1);
+        let _ = circ_buffer.drain(0..2);
+    }
+
+    #[test]
+    fn test_drain_dropped_not_consumed() {
+        let mut circ_buffer = RingBuffer::<_, 5>::default();
+        for i in 0..5 {
+            circ_buffer.push(format!("{i}"));
+        }
+        // Drop the Drain without consuming it; the tail must still shift down.
+        drop(circ_buffer.drain(1..3));
+        assert_eq!(
+            circ_buffer.iter().collect::<Vec<_>>(),
+            vec!["0", "3", "4"]
+        );
+    }
+
+    #[test]
+    fn test_eq_rotation_agnostic() {
+        // `a` and `b` hold the same logical contents but with different internal offsets.
+        let mut a = RingBuffer::<_, 4>::default();
+        for i in 0..4 {
+            a.push(i);
+        }
+        let mut b = RingBuffer::<_, 4>::default();
+        for i in 0..6 {
+            b.push(i - 2);
+        }
+        assert_eq!(a, b);
+
+        let mut hasher_a = std::collections::hash_map::DefaultHasher::new();
+        let mut hasher_b = std::collections::hash_map::DefaultHasher::new();
+        std::hash::Hash::hash(&a, &mut hasher_a);
+        std::hash::Hash::hash(&b, &mut hasher_b);
+        assert_eq!(
+            std::hash::Hasher::finish(&hasher_a),
+            std::hash::Hasher::finish(&hasher_b)
+        );
+
+        let mut c = RingBuffer::<_, 4>::default();
+        c.push(0);
+        c.push(1);
+        assert!(c < a);
+    }
+
+    #[test]
+    fn test_pop_empty_zero_sized() {
+        let mut circ_buffer = RingBuffer::<i32, 0>::new();
+        circ_buffer.push_front(1);
+        assert_eq!(circ_buffer.pop_front(), None);
+        assert_eq!(circ_buffer.pop_back(), None);
+    }
+
+    #[test]
+    fn test_double_ended_and_len() {
+        let mut circ_buffer = RingBuffer::<_, 4>::default();
+        for i in 0..6 {
+            circ_buffer.push(i);
+        }
+        // Logical contents [2, 3, 4, 5].
+        assert_eq!(circ_buffer.iter().len(), 4);
+        assert_eq!(
+            circ_buffer.iter().rev().collect::<Vec<_>>(),
+            vec![&5, &4, &3, &2]
+        );
+        let mut it = circ_buffer.iter();
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.next_back(), Some(&5));
+        assert_eq!(it.next(), Some(&3));
+        assert_eq!(it.next_back(), Some(&4));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+
+        let owned = circ_buffer.into_iter().rev().collect::<Vec<_>>();
+        assert_eq!(owned, vec![5, 4, 3, 2]);
+    }
+
     #[cfg(feature = "serde")]
     mod serde {
         use crate::*;